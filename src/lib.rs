@@ -1,3 +1,7 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
 /// The `Apply` trait makes it possible to apply a unary function inside a wrapper. The `apply` method does not take ownership over the wrapper, but it does take ownership over the argument.
 pub trait Apply {
     type In;
@@ -111,6 +115,395 @@ impl<FIn: 'static, FOut: 'static> FBox<FIn, FOut> {
     pub fn and_then_b<GOut: 'static>(self, other: FBox<FOut, GOut>) -> FBox<FIn, GOut> {
         FBox::new(move |x| (other.f)((self.f)(x)))
     }
+
+    /// `FBox` is lazy and only calls its function on demand; `memoize` takes that one step further by remembering the result of each distinct argument in a `RefCell<HashMap<FIn, FOut>>`, so repeated calls with the same argument skip re-running the underlying function and return a clone of the cached result instead.
+    /// This relies on interior mutability and is not thread-safe: the cache is a plain `RefCell`, so the memoized `FBox` must stay on a single thread. The cache also grows without bound; use `memoize_bounded` if that is a concern.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let calls = Rc::new(Cell::new(0));
+    /// let calls_clone = Rc::clone(&calls);
+    /// let memoized = FBox::new(move |x: i32| {
+    ///     calls_clone.set(calls_clone.get() + 1);
+    ///     x * x
+    /// }).memoize();
+    ///
+    /// assert_eq!(memoized.apply(3), 9);
+    /// assert_eq!(memoized.apply(3), 9);
+    /// assert_eq!(calls.get(), 1);
+    ///```
+    pub fn memoize(self) -> FBox<FIn, FOut>
+        where FIn: Eq + Hash + Clone, FOut: Clone {
+        let cache: RefCell<HashMap<FIn, FOut>> = RefCell::new(HashMap::new());
+
+        FBox::new(move |a: FIn| {
+            if let Some(cached) = cache.borrow().get(&a) {
+                return cached.clone();
+            }
+
+            let result = (self.f)(a.clone());
+            cache.borrow_mut().insert(a, result.clone());
+            result
+        })
+    }
+
+    /// Similar to `memoize`, except the cache is bounded to `capacity` entries. Once the cache is full, inserting a new entry evicts the oldest one first (FIFO, not least-recently-used). A `capacity` of `0` disables caching entirely rather than permanently holding one entry.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let memoized = FBox::new(|x: i32| x * x).memoize_bounded(1);
+    ///
+    /// assert_eq!(memoized.apply(3), 9);
+    /// assert_eq!(memoized.apply(4), 16);
+    ///```
+    pub fn memoize_bounded(self, capacity: usize) -> FBox<FIn, FOut>
+        where FIn: Eq + Hash + Clone, FOut: Clone {
+        let cache: RefCell<HashMap<FIn, FOut>> = RefCell::new(HashMap::new());
+        let order: RefCell<VecDeque<FIn>> = RefCell::new(VecDeque::new());
+
+        FBox::new(move |a: FIn| {
+            if capacity == 0 {
+                return (self.f)(a);
+            }
+
+            if let Some(cached) = cache.borrow().get(&a) {
+                return cached.clone();
+            }
+
+            let result = (self.f)(a.clone());
+
+            let mut cache = cache.borrow_mut();
+            let mut order = order.borrow_mut();
+            if cache.len() >= capacity {
+                if let Some(oldest) = order.pop_front() {
+                    cache.remove(&oldest);
+                }
+            }
+            cache.insert(a.clone(), result.clone());
+            order.push_back(a);
+
+            result
+        })
+    }
+
+    /// Lifts the wrapped function so it can be applied to an `Option<FIn>` instead of a bare `FIn`, running the function over the contained value and leaving `None` untouched. This is the functor `map` for `Option`.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let lifted = FBox::new(|x: i32| x + 1).lift_option();
+    ///
+    /// assert_eq!(lifted.apply(Some(3)), Some(4));
+    /// assert_eq!(lifted.apply(None), None);
+    ///```
+    pub fn lift_option(self) -> FBox<Option<FIn>, Option<FOut>> {
+        FBox::new(move |o: Option<FIn>| o.map(|x| (self.f)(x)))
+    }
+
+    /// Lifts the wrapped function so it can be applied to a `Result<FIn, E>` instead of a bare `FIn`, running the function over the `Ok` value and leaving `Err` untouched. This is the functor `map` for `Result`.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let lifted = FBox::new(|x: i32| x + 1).lift_result();
+    ///
+    /// assert_eq!(lifted.apply(Ok::<i32, &str>(3)), Ok(4));
+    /// assert_eq!(lifted.apply(Err("oops")), Err("oops"));
+    ///```
+    pub fn lift_result<E: 'static>(self) -> FBox<Result<FIn, E>, Result<FOut, E>> {
+        FBox::new(move |r: Result<FIn, E>| r.map(|x| (self.f)(x)))
+    }
+
+    /// Lifts the wrapped function so it can be applied to a `Vec<FIn>` instead of a bare `FIn`, running the function over every element. This is the functor `map` for `Vec`.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let lifted = FBox::new(|x: i32| x + 1).lift_vec();
+    ///
+    /// assert_eq!(lifted.apply(vec![1, 2, 3]), vec![2, 3, 4]);
+    ///```
+    pub fn lift_vec(self) -> FBox<Vec<FIn>, Vec<FOut>> {
+        FBox::new(move |v: Vec<FIn>| v.into_iter().map(|x| (self.f)(x)).collect())
+    }
+}
+
+/// `f >> g` is point-free shorthand for `f.and_then_b(g)`: data flows left to right, so the result is `g(f(x))`.
+/// # Examples
+///```
+/// # use fbox::*;
+/// assert_eq!(
+///     16,
+///     (FBox::new(|x| x + 1) >> FBox::new(|x| x * x)).apply(3)
+/// );
+///```
+impl<FIn: 'static, FOut: 'static, GOut: 'static> std::ops::Shr<FBox<FOut, GOut>> for FBox<FIn, FOut> {
+    type Output = FBox<FIn, GOut>;
+
+    fn shr(self, other: FBox<FOut, GOut>) -> FBox<FIn, GOut> {
+        self.and_then_b(other)
+    }
+}
+
+/// `f << g` is point-free shorthand for `f.compose_b(g)`: data flows right to left, so the result is `f(g(x))`.
+/// # Examples
+///```
+/// # use fbox::*;
+/// assert_eq!(
+///     10,
+///     (FBox::new(|x| x + 1) << FBox::new(|x| x * x)).apply(3)
+/// );
+///```
+impl<FIn: 'static, FOut: 'static, GIn: 'static> std::ops::Shl<FBox<GIn, FIn>> for FBox<FIn, FOut> {
+    type Output = FBox<GIn, FOut>;
+
+    fn shl(self, other: FBox<GIn, FIn>) -> FBox<GIn, FOut> {
+        self.compose_b(other)
+    }
+}
+
+impl<FIn: 'static, T: 'static, E: 'static> FBox<FIn, Result<T, E>> {
+    /// Kleisli composition for a fallible `FBox`: applies `f`, and on `Ok(t)` runs `g(t)`, propagating the first `Err` unchanged without any manual matching. This lets fallible pipelines such as `parse` composed with `checked_div` short-circuit automatically.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let parse_and_halve = FBox::new(|s: &str| s.parse::<i32>().map_err(|_| "not a number"))
+    ///     .and_then_ok(|n| if n % 2 == 0 { Ok(n / 2) } else { Err("not even") });
+    ///
+    /// assert_eq!(parse_and_halve.apply("4"), Ok(2));
+    /// assert_eq!(parse_and_halve.apply("3"), Err("not even"));
+    /// assert_eq!(parse_and_halve.apply("x"), Err("not a number"));
+    ///```
+    pub fn and_then_ok<U: 'static>(self, g: impl Fn(T) -> Result<U, E> + 'static) -> FBox<FIn, Result<U, E>> {
+        FBox::new(move |x| (self.f)(x).and_then(&g))
+    }
+
+    /// Similar to `and_then_ok`, except `g` is infallible and only transforms the success value, leaving any `Err` untouched.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let parse_and_double = FBox::new(|s: &str| s.parse::<i32>().map_err(|_| "not a number"))
+    ///     .map_ok(|n| n * 2);
+    ///
+    /// assert_eq!(parse_and_double.apply("4"), Ok(8));
+    /// assert_eq!(parse_and_double.apply("x"), Err("not a number"));
+    ///```
+    pub fn map_ok<U: 'static>(self, g: impl Fn(T) -> U + 'static) -> FBox<FIn, Result<U, E>> {
+        FBox::new(move |x| (self.f)(x).map(&g))
+    }
+}
+
+impl<FIn: 'static, T: 'static> FBox<FIn, Option<T>> {
+    /// Kleisli composition for an `FBox` returning `Option`: applies `f`, and on `Some(t)` runs `g(t)`, propagating `None` unchanged.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let first_and_double = FBox::new(|v: Vec<i32>| v.first().cloned())
+    ///     .and_then_some(|n| if n > 0 { Some(n * 2) } else { None });
+    ///
+    /// assert_eq!(first_and_double.apply(vec![3, 4]), Some(6));
+    /// assert_eq!(first_and_double.apply(vec![]), None);
+    ///```
+    pub fn and_then_some<U: 'static>(self, g: impl Fn(T) -> Option<U> + 'static) -> FBox<FIn, Option<U>> {
+        FBox::new(move |x| (self.f)(x).and_then(&g))
+    }
+
+    /// Similar to `and_then_some`, except `g` is infallible and only transforms the `Some` value, leaving `None` untouched.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let first_and_double = FBox::new(|v: Vec<i32>| v.first().cloned())
+    ///     .map_some(|n| n * 2);
+    ///
+    /// assert_eq!(first_and_double.apply(vec![3, 4]), Some(6));
+    /// assert_eq!(first_and_double.apply(vec![]), None);
+    ///```
+    pub fn map_some<U: 'static>(self, g: impl Fn(T) -> U + 'static) -> FBox<FIn, Option<U>> {
+        FBox::new(move |x| (self.f)(x).map(&g))
+    }
+}
+
+/// `FBox2` is a generic wrapper of a binary function. It extends the ideas behind `FBox` to functions of arity two, so you don't have to manually tuple-up multi-argument functions.
+pub struct FBox2<A, B, Out> {
+    f: Box<Fn(A, B) -> Out>
+}
+
+impl<A: 'static, B: 'static, Out: 'static> FBox2<A, B, Out> {
+    /// Creates a new `FBox2` from a binary function.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let add = FBox2::new(|a, b| a + b);
+    /// assert_eq!(add.apply(3, 4), 7);
+    ///```
+    pub fn new(f: impl Fn(A, B) -> Out + 'static) -> FBox2<A, B, Out> {
+        FBox2 { f: Box::new(f) }
+    }
+
+    /// Applies the wrapped binary function to two arguments. Does not take ownership over the `FBox2`, but does take ownership over the arguments.
+    pub fn apply(&self, a: A, b: B) -> Out {
+        (self.f)(a, b)
+    }
+
+    /// Similar to `apply`, except it takes ownership over the `FBox2`.
+    pub fn apply_drop(self, a: A, b: B) -> Out {
+        (self.f)(a, b)
+    }
+
+    /// Turns an `FBox2<A, B, Out>` into its curried form `FBox<A, FBox<B, Out>>`, so the two arguments can be supplied one at a time: `add.curry().apply(3).apply(4)`.
+    /// The wrapped function is shared through an `Rc`, so the curried form can be applied more than once; the first argument is cloned on every application of each `FBox<B, Out>` it produces, which is what lets those be applied more than once too.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let add = FBox2::new(|a: i32, b: i32| a + b);
+    /// assert_eq!(add.curry().apply(3).apply(4), 7);
+    ///```
+    pub fn curry(self) -> FBox<A, FBox<B, Out>>
+        where A: Clone {
+        let f = std::rc::Rc::new(self.f);
+        FBox::new(move |a: A| {
+            let f = std::rc::Rc::clone(&f);
+            FBox::new(move |b: B| (f)(a.clone(), b))
+        })
+    }
+
+    /// Binds the first argument and returns a new unary `FBox` capturing it: `add.apply_partial(3).apply(4)`.
+    /// This is a one-shot partial application in the sense that it consumes the `FBox2` — to partially apply the same underlying function with a different first argument, build a new `FBox2` from the original closure.
+    /// The first argument is cloned on every application of the returned `FBox`, which is what lets that `FBox` be applied more than once.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let add = FBox2::new(|a: i32, b: i32| a + b);
+    /// assert_eq!(add.apply_partial(3).apply(4), 7);
+    ///```
+    pub fn apply_partial(self, a: A) -> FBox<B, Out>
+        where A: Clone {
+        FBox::new(move |b| (self.f)(a.clone(), b))
+    }
+}
+
+/// `FBox3` is a generic wrapper of a ternary function, following the same pattern as `FBox2` for functions of arity three.
+pub struct FBox3<A, B, C, Out> {
+    f: Box<Fn(A, B, C) -> Out>
+}
+
+impl<A: 'static, B: 'static, C: 'static, Out: 'static> FBox3<A, B, C, Out> {
+    /// Creates a new `FBox3` from a ternary function.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let add3 = FBox3::new(|a, b, c| a + b + c);
+    /// assert_eq!(add3.apply(3, 4, 5), 12);
+    ///```
+    pub fn new(f: impl Fn(A, B, C) -> Out + 'static) -> FBox3<A, B, C, Out> {
+        FBox3 { f: Box::new(f) }
+    }
+
+    /// Applies the wrapped ternary function to three arguments. Does not take ownership over the `FBox3`, but does take ownership over the arguments.
+    pub fn apply(&self, a: A, b: B, c: C) -> Out {
+        (self.f)(a, b, c)
+    }
+
+    /// Similar to `apply`, except it takes ownership over the `FBox3`.
+    pub fn apply_drop(self, a: A, b: B, c: C) -> Out {
+        (self.f)(a, b, c)
+    }
+}
+
+/// `FBoxOnce` is a generic wrapper of a unary function that may only be called once. Unlike `FBox`, which wraps `Fn`, `FBoxOnce` wraps `FnOnce`, so the function inside is allowed to move out of its captured environment — for example to send an owned value through a channel or to close a file handle.
+pub struct FBoxOnce<FIn, FOut> {
+    f: Box<FnOnce(FIn) -> FOut>
+}
+
+impl<FIn: 'static, FOut: 'static> FBoxOnce<FIn, FOut> {
+    /// Creates a new `FBoxOnce` from a unary `FnOnce` function.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let s = String::from("hello");
+    /// let fbox_once = FBoxOnce::new(move |suffix: String| s + &suffix);
+    ///
+    /// assert_eq!(fbox_once.call_once(String::from(" world")), "hello world");
+    ///```
+    pub fn new(f: impl FnOnce(FIn) -> FOut + 'static) -> FBoxOnce<FIn, FOut> {
+        FBoxOnce { f: Box::new(f) }
+    }
+
+    /// Consumes the `FBoxOnce` and calls the wrapped function exactly once, the same way `FnOnce::call_once` would.
+    pub fn call_once(self, a: FIn) -> FOut {
+        (self.f)(a)
+    }
+
+    /// Similar to `FBox::compose`, except both the outer and the composed function are only called once.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let s = String::from("hello");
+    /// let fbox_once = FBoxOnce::new(move |n: i32| format!("{}{}", s, n)).compose(|x: i32| x * x);
+    ///
+    /// assert_eq!(fbox_once.call_once(3), "hello9");
+    ///```
+    pub fn compose<GIn: 'static>(self, g: impl FnOnce(GIn) -> FIn + 'static) -> FBoxOnce<GIn, FOut> {
+        FBoxOnce::new(move |x| (self.f)(g(x)))
+    }
+
+    /// Similar to `FBox::and_then`, except both the outer and the following function are only called once.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let s = String::from("hello");
+    /// let fbox_once = FBoxOnce::new(move |n: i32| s + &n.to_string()).and_then(|s: String| s.len());
+    ///
+    /// assert_eq!(fbox_once.call_once(3), 6);
+    ///```
+    pub fn and_then<GOut: 'static>(self, g: impl FnOnce(FOut) -> GOut + 'static) -> FBoxOnce<FIn, GOut> {
+        FBoxOnce::new(move |x| g((self.f)(x)))
+    }
+}
+
+impl<FIn: 'static, FOut: 'static> ApplyDrop for FBoxOnce<FIn, FOut> {
+    type In = FIn;
+    type Out = FOut;
+
+    fn apply_drop(self, a: FIn) -> FOut {
+        self.call_once(a)
+    }
+}
+
+impl<FIn: 'static, FOut: 'static> From<FBox<FIn, FOut>> for FBoxOnce<FIn, FOut> {
+    /// Upgrades an ordinary `Fn`-based `FBox` into a one-shot `FBoxOnce`.
+    fn from(fbox: FBox<FIn, FOut>) -> FBoxOnce<FIn, FOut> {
+        FBoxOnce::new(move |a| fbox.apply_drop(a))
+    }
+}
+
+/// `FBoxMut` is a generic wrapper of a unary function with mutable captured state, such as an accumulating counter. Unlike `FBox`, which wraps `Fn`, `FBoxMut` wraps `FnMut`, so calling it requires a mutable reference to the `FBoxMut`.
+pub struct FBoxMut<FIn, FOut> {
+    f: Box<FnMut(FIn) -> FOut>
+}
+
+impl<FIn: 'static, FOut: 'static> FBoxMut<FIn, FOut> {
+    /// Creates a new `FBoxMut` from a unary `FnMut` function.
+    /// # Examples
+    ///```
+    /// # use fbox::*;
+    /// let mut count = 0;
+    /// let mut counter = FBoxMut::new(move |n: i32| {
+    ///     count += n;
+    ///     count
+    /// });
+    ///
+    /// assert_eq!(counter.apply_mut(1), 1);
+    /// assert_eq!(counter.apply_mut(2), 3);
+    ///```
+    pub fn new(f: impl FnMut(FIn) -> FOut + 'static) -> FBoxMut<FIn, FOut> {
+        FBoxMut { f: Box::new(f) }
+    }
+
+    /// Applies the wrapped function, taking a mutable reference over the `FBoxMut` so the captured state can be updated.
+    pub fn apply_mut(&mut self, a: FIn) -> FOut {
+        (self.f)(a)
+    }
 }
 
 #[cfg(test)]
@@ -126,4 +519,197 @@ mod tests {
             fb1.apply_drop(3)
         );
     }
+
+    #[test]
+    fn fbox2_apply() {
+        let add = FBox2::new(|a, b| a + b);
+
+        assert_eq!(add.apply(3, 4), 7);
+    }
+
+    #[test]
+    fn fbox2_curry() {
+        let add = FBox2::new(|a: i32, b: i32| a + b);
+        let curried = add.curry();
+
+        assert_eq!(curried.apply(3).apply(4), 7);
+    }
+
+    #[test]
+    fn fbox2_apply_partial() {
+        let add = FBox2::new(|a: i32, b: i32| a + b);
+
+        assert_eq!(add.apply_partial(3).apply(4), 7);
+    }
+
+    #[test]
+    fn fbox3_apply() {
+        let add3 = FBox3::new(|a, b, c| a + b + c);
+
+        assert_eq!(add3.apply(3, 4, 5), 12);
+    }
+
+    #[test]
+    fn fbox_once_call_once() {
+        let s = String::from("hello");
+        let fbox_once = FBoxOnce::new(move |suffix: String| s + &suffix);
+
+        assert_eq!(fbox_once.call_once(String::from(" world")), "hello world");
+    }
+
+    #[test]
+    fn fbox_once_compose_and_then() {
+        let fbox_once = FBoxOnce::new(|x: i32| x + 1)
+            .compose(|x: i32| x * x)
+            .and_then(|x: i32| x.to_string());
+
+        assert_eq!(fbox_once.call_once(3), "10");
+    }
+
+    #[test]
+    fn fbox_once_from_fbox() {
+        let fbox = FBox::new(|x: i32| x + 1);
+        let fbox_once = FBoxOnce::from(fbox);
+
+        assert_eq!(fbox_once.call_once(3), 4);
+    }
+
+    #[test]
+    fn fbox_mut_apply_mut() {
+        let mut count = 0;
+        let mut counter = FBoxMut::new(move |n: i32| {
+            count += n;
+            count
+        });
+
+        assert_eq!(counter.apply_mut(1), 1);
+        assert_eq!(counter.apply_mut(2), 3);
+    }
+
+    #[test]
+    fn memoize_caches_results() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = Rc::clone(&calls);
+        let memoized = FBox::new(move |x: i32| {
+            calls_clone.set(calls_clone.get() + 1);
+            x * x
+        }).memoize();
+
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(memoized.apply(4), 16);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn memoize_bounded_evicts_oldest() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = Rc::clone(&calls);
+        let memoized = FBox::new(move |x: i32| {
+            calls_clone.set(calls_clone.get() + 1);
+            x * x
+        }).memoize_bounded(1);
+
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(memoized.apply(4), 16);
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn memoize_bounded_zero_capacity_never_caches() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = Rc::clone(&calls);
+        let memoized = FBox::new(move |x: i32| {
+            calls_clone.set(calls_clone.get() + 1);
+            x * x
+        }).memoize_bounded(0);
+
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(memoized.apply(3), 9);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn shr_is_and_then_b() {
+        let piped = FBox::new(|x: i32| x + 1) >> FBox::new(|x: i32| x * x);
+
+        assert_eq!(piped.apply(3), 16);
+    }
+
+    #[test]
+    fn shl_is_compose_b() {
+        let piped = FBox::new(|x: i32| x + 1) << FBox::new(|x: i32| x * x);
+
+        assert_eq!(piped.apply(3), 10);
+    }
+
+    #[test]
+    fn and_then_ok_short_circuits() {
+        let parse_and_halve = FBox::new(|s: &str| s.parse::<i32>().map_err(|_| "not a number"))
+            .and_then_ok(|n| if n % 2 == 0 { Ok(n / 2) } else { Err("not even") });
+
+        assert_eq!(parse_and_halve.apply("4"), Ok(2));
+        assert_eq!(parse_and_halve.apply("3"), Err("not even"));
+        assert_eq!(parse_and_halve.apply("x"), Err("not a number"));
+    }
+
+    #[test]
+    fn map_ok_transforms_success_value() {
+        let parse_and_double = FBox::new(|s: &str| s.parse::<i32>().map_err(|_| "not a number"))
+            .map_ok(|n| n * 2);
+
+        assert_eq!(parse_and_double.apply("4"), Ok(8));
+        assert_eq!(parse_and_double.apply("x"), Err("not a number"));
+    }
+
+    #[test]
+    fn and_then_some_short_circuits() {
+        let first_and_double = FBox::new(|v: Vec<i32>| v.first().cloned())
+            .and_then_some(|n| if n > 0 { Some(n * 2) } else { None });
+
+        assert_eq!(first_and_double.apply(vec![3, 4]), Some(6));
+        assert_eq!(first_and_double.apply(vec![]), None);
+    }
+
+    #[test]
+    fn map_some_transforms_some_value() {
+        let first_and_double = FBox::new(|v: Vec<i32>| v.first().cloned())
+            .map_some(|n| n * 2);
+
+        assert_eq!(first_and_double.apply(vec![3, 4]), Some(6));
+        assert_eq!(first_and_double.apply(vec![]), None);
+    }
+
+    #[test]
+    fn lift_option_maps_inner_value() {
+        let lifted = FBox::new(|x: i32| x + 1).lift_option();
+
+        assert_eq!(lifted.apply(Some(3)), Some(4));
+        assert_eq!(lifted.apply(None), None);
+    }
+
+    #[test]
+    fn lift_result_maps_ok_value() {
+        let lifted = FBox::new(|x: i32| x + 1).lift_result();
+
+        assert_eq!(lifted.apply(Ok::<i32, &str>(3)), Ok(4));
+        assert_eq!(lifted.apply(Err("oops")), Err("oops"));
+    }
+
+    #[test]
+    fn lift_vec_maps_every_element() {
+        let lifted = FBox::new(|x: i32| x + 1).lift_vec();
+
+        assert_eq!(lifted.apply(vec![1, 2, 3]), vec![2, 3, 4]);
+    }
 }
\ No newline at end of file